@@ -1,6 +1,7 @@
 //! Arithmetic operations for tuple indices.
 
 use crate::prelude::*;
+use typed_tuple_macros::impl_arithmetic;
 
 /// Trait for adding two tuple indices.
 ///
@@ -71,3 +72,126 @@ pub trait TupleIndexSaturatingSub<Other> {
     /// The resulting tuple index type after saturating subtraction.
     type Output: TupleIndex;
 }
+
+/// Resolves a `usize` known at the type level back to its concrete
+/// `TupleIndexN` marker.
+///
+/// Only indices within the supported marker range (`0..=64`) implement this
+/// trait. The arithmetic traits in this module name their result marker
+/// directly rather than going through `FromIndex`, and are likewise only
+/// implemented where the result falls within that range, so an operation
+/// whose result falls outside it fails to resolve at compile time rather
+/// than silently wrapping.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type Five = <() as FromIndex<5>>::Output;
+/// assert_eq!(<Five as TupleIndex>::INDEX, 5);
+/// ```
+pub trait FromIndex<const N: usize> {
+    /// The concrete `TupleIndexN` marker for `N`.
+    type Output: TupleIndex;
+}
+
+/// Trait for multiplying two tuple indices.
+///
+/// This trait allows compile-time multiplication of tuple index positions.
+/// It's only implemented for index combinations whose product is a valid
+/// index (i.e., the product must be less than the maximum tuple size).
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// // TupleIndex2 * TupleIndex3 = TupleIndex6
+/// type Result = <TupleIndex2 as TupleIndexMul<TupleIndex3>>::Output;
+/// assert_eq!(<Result as TupleIndex>::INDEX, 6);
+/// ```
+pub trait TupleIndexMul<Other> {
+    /// The resulting tuple index type after multiplication.
+    type Output: TupleIndex;
+}
+
+/// Trait for selecting the smaller of two tuple indices.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// // min(TupleIndex2, TupleIndex5) = TupleIndex2
+/// type Result = <TupleIndex2 as TupleIndexMin<TupleIndex5>>::Output;
+/// assert_eq!(<Result as TupleIndex>::INDEX, 2);
+/// ```
+pub trait TupleIndexMin<Other> {
+    /// The smaller of `Self` and `Other`.
+    type Output: TupleIndex;
+}
+
+/// Trait for selecting the larger of two tuple indices.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// // max(TupleIndex2, TupleIndex5) = TupleIndex5
+/// type Result = <TupleIndex2 as TupleIndexMax<TupleIndex5>>::Output;
+/// assert_eq!(<Result as TupleIndex>::INDEX, 5);
+/// ```
+pub trait TupleIndexMax<Other> {
+    /// The larger of `Self` and `Other`.
+    type Output: TupleIndex;
+}
+
+/// Type-level marker for `true`, returned as the `Output` of the
+/// `TupleIndexLt`/`TupleIndexLe`/`TupleIndexEq` comparison traits.
+pub struct TrueIndex;
+
+/// Type-level marker for `false`, returned as the `Output` of the
+/// `TupleIndexLt`/`TupleIndexLe`/`TupleIndexEq` comparison traits.
+pub struct FalseIndex;
+
+/// Trait for comparing two tuple indices for a strict less-than relation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type Result = <TupleIndex2 as TupleIndexLt<TupleIndex5>>::Output;
+/// let _: Result = TrueIndex;
+/// ```
+pub trait TupleIndexLt<Other> {
+    /// [`TrueIndex`] if `Self < Other`, [`FalseIndex`] otherwise.
+    type Output;
+}
+
+/// Trait for comparing two tuple indices for a less-than-or-equal relation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type Result = <TupleIndex5 as TupleIndexLe<TupleIndex5>>::Output;
+/// let _: Result = TrueIndex;
+/// ```
+pub trait TupleIndexLe<Other> {
+    /// [`TrueIndex`] if `Self <= Other`, [`FalseIndex`] otherwise.
+    type Output;
+}
+
+/// Trait for comparing two tuple indices for equality.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type Result = <TupleIndex2 as TupleIndexEq<TupleIndex5>>::Output;
+/// let _: Result = FalseIndex;
+/// ```
+pub trait TupleIndexEq<Other> {
+    /// [`TrueIndex`] if `Self == Other`, [`FalseIndex`] otherwise.
+    type Output;
+}
+
+impl_arithmetic!(64);
@@ -0,0 +1,70 @@
+//! Submodule providing `ChainLeft`/`ChainRight`, directional wrappers around
+//! [`TypedJoin`] used by the split/swap/push machinery to reassemble tuples
+//! after splitting them apart.
+
+use crate::prelude::*;
+
+/// Trait for appending `Rhs` to the right of `Self`.
+///
+/// Equivalent to [`TypedJoin::join`], named directionally for call sites
+/// that read more naturally as "attach this tuple to the right".
+pub trait ChainRight<Rhs> {
+    /// The tuple type produced by appending `Rhs` to `Self`.
+    type Output;
+
+    /// Appends `rhs` to the right of `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let left = (0u8, 1u16);
+    /// let right = (2u32, 3u64);
+    /// assert_eq!(left.chain_right(right), (0u8, 1u16, 2u32, 3u64));
+    /// ```
+    fn chain_right(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<T, Rhs> ChainRight<Rhs> for T
+where
+    T: TypedJoin<Rhs>,
+{
+    type Output = <T as TypedJoin<Rhs>>::Output;
+
+    #[inline]
+    fn chain_right(self, rhs: Rhs) -> Self::Output {
+        self.join(rhs)
+    }
+}
+
+/// Trait for prepending `Rhs` to the left of `Self`.
+///
+/// Equivalent to [`TypedJoin::join`] with the operands swapped, named
+/// directionally for call sites that read more naturally as "attach this
+/// tuple to the left".
+pub trait ChainLeft<Rhs> {
+    /// The tuple type produced by prepending `Rhs` to `Self`.
+    type Output;
+
+    /// Prepends `rhs` to the left of `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let right = (2u32, 3u64);
+    /// let left = (0u8, 1u16);
+    /// assert_eq!(right.chain_left(left), (0u8, 1u16, 2u32, 3u64));
+    /// ```
+    fn chain_left(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<T, Rhs> ChainLeft<Rhs> for T
+where
+    Rhs: TypedJoin<T>,
+{
+    type Output = <Rhs as TypedJoin<T>>::Output;
+
+    #[inline]
+    fn chain_left(self, rhs: Rhs) -> Self::Output {
+        rhs.join(self)
+    }
+}
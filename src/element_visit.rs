@@ -0,0 +1,77 @@
+//! Heterogeneous `for_each`/`fold` traversal over every element of a tuple,
+//! regardless of how many different types it mixes.
+//!
+//! [`TypedTuple::apply`](crate::TypedTuple::apply) operates on a single
+//! indexed element with a closure; [`TupleVisit`] generalizes this into a
+//! whole-tuple traversal by handing every element, in order, to a
+//! polymorphic [`ElementVisitor`] (or [`ElementFolder`]) that is itself
+//! generic over the element type.
+//!
+//! See [`TypedVisit`](crate::TypedVisit)'s module docs for how this relates
+//! to [`TupleMap`](crate::TupleMap)/[`TupleFold`](crate::TupleFold) and
+//! [`TypedVisit`](crate::TypedVisit), the other two "visit every element"
+//! traits in this crate, and which to reach for.
+
+use crate::prelude::*;
+
+/// A visitor that can be applied to an element of any type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct ResetToDefault;
+///
+/// impl ElementVisitor for ResetToDefault {
+///     fn visit<T: Default>(&mut self, el: &mut T) {
+///         *el = T::default();
+///     }
+/// }
+///
+/// let mut tuple = (1u8, "hi", 2.5f32);
+/// tuple.for_each(&mut ResetToDefault);
+/// assert_eq!(tuple, (0u8, "", 0.0f32));
+/// ```
+pub trait ElementVisitor {
+    /// Visit a single element, mutating it in place.
+    fn visit<T>(&mut self, el: &mut T);
+}
+
+/// A folder that threads an accumulator across every element of a tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct CountElements;
+///
+/// impl ElementFolder<usize> for CountElements {
+///     fn fold<T>(&mut self, acc: usize, _el: &T) -> usize {
+///         acc + 1
+///     }
+/// }
+///
+/// let tuple = (1u8, "hi", 2.5f32);
+/// assert_eq!(tuple.fold(&mut CountElements, 0), 3);
+/// ```
+pub trait ElementFolder<Acc> {
+    /// Fold a single element into the running accumulator.
+    fn fold<T>(&mut self, acc: Acc, el: &T) -> Acc;
+}
+
+/// Trait exposing whole-tuple traversal via an [`ElementVisitor`] or
+/// [`ElementFolder`].
+///
+/// Unlike [`TupleMap`](crate::TupleMap)/[`TupleFold`](crate::TupleFold),
+/// which require a `Mapper`/`Folder` bound per element type, `TupleVisit`
+/// only requires the visitor to be generic over `T`, so the same visitor
+/// can be reused across differently-typed tuples.
+pub trait TupleVisit {
+    /// Visit every element of the tuple, in order, with `v`.
+    fn for_each<V: ElementVisitor>(&mut self, v: &mut V);
+
+    /// Fold every element of the tuple, in order, into `init` using `v`.
+    fn fold<V: ElementFolder<Acc>, Acc>(&self, v: &mut V, init: Acc) -> Acc;
+}
+
+typed_tuple_macros::impl_element_visit!(12);
@@ -0,0 +1,47 @@
+use typed_tuple_macros::impl_homogeneous;
+
+/// ## Homogeneous
+///
+/// [`Homogeneous`] is implemented for tuples whose elements are all the same
+/// type `T`, bridging such tuples into the normal array/slice ecosystem.
+///
+/// ```rust
+/// # use typed_tuple::Homogeneous;
+/// let tuple = (1u8, 2u8, 3u8);
+/// let array: [u8; 3] = tuple.into_array();
+/// assert_eq!(array, [1, 2, 3]);
+/// ```
+///
+/// ## Scope
+///
+/// The original request for this trait (and its `TupleElements` extension)
+/// also asked for borrowing `as_slice`/`as_mut_slice`/`as_array`/
+/// `as_mut_array` accessors. Those are deliberately not implemented: a
+/// plain tuple's field layout is not guaranteed by the language, even when
+/// every field is the same `T`, so producing a `&[T]`/`&[T; N]` view into
+/// `&Self` without copying would have to reinterpret the tuple's raw bytes,
+/// which is unsound. This is closed out as delivered-by-design with that
+/// narrower scope: [`Homogeneous::into_array`] (and
+/// [`TupleElements::elements`]) are the supported, sound path, going
+/// through an owned array rather than borrowing through the tuple.
+pub trait Homogeneous<T> {
+    /// The number of elements in the tuple.
+    const LEN: usize;
+
+    /// Converts the tuple into a fixed-size array, consuming it.
+    ///
+    /// # Example
+    /// ```
+    /// # use typed_tuple::Homogeneous;
+    /// let tuple = (1u8, 2u8, 3u8);
+    /// assert_eq!(tuple.into_array(), [1u8, 2, 3]);
+    /// ```
+    ///
+    /// See the [trait-level scope note](Homogeneous#scope) for why there is
+    /// no borrowing `as_slice`/`as_mut_slice` counterpart.
+    fn into_array(self) -> [T; Self::LEN]
+    where
+        Self: Sized;
+}
+
+impl_homogeneous!(12);
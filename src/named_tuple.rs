@@ -0,0 +1,145 @@
+//! Opt-in runtime name lookup for tuple elements, complementing the purely
+//! positional [`TupleIndex`] design.
+
+use crate::prelude::*;
+use core::marker::PhantomData;
+
+/// Marker trait associating a compile-time name with a type.
+///
+/// `str` is not usable as a `const` generic parameter on stable Rust, so
+/// names are attached via a zero-sized marker type instead, the same
+/// approach `TupleKey` uses to associate a marker with a tuple index.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct Age;
+///
+/// impl HasName for Age {
+///     const NAME: &'static str = "age";
+/// }
+/// ```
+pub trait HasName {
+    /// The runtime name associated with this marker.
+    const NAME: &'static str;
+}
+
+/// Wraps a value with a compile-time name label `N`, usable as a tuple
+/// element.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct Age;
+///
+/// impl HasName for Age {
+///     const NAME: &'static str = "age";
+/// }
+///
+/// let tuple = (Named::<_, Age>::new(27u8),);
+/// assert_eq!(*tuple.0.value(), 27u8);
+/// ```
+pub struct Named<T, N> {
+    value: T,
+    _marker: PhantomData<N>,
+}
+
+impl<T, N: HasName> Named<T, N> {
+    /// Wrap `value`, labeling it with the name `N::NAME`.
+    pub fn new(value: T) -> Self {
+        Named {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Helper trait giving [`NamedTuple`]/[`MatchName`] uniform access to a
+/// [`Named`] element's name and wrapped value.
+pub trait NamedElement {
+    /// The type of the wrapped value.
+    type Value;
+
+    /// The name associated with this element.
+    const NAME: &'static str;
+
+    /// Get a reference to the wrapped value.
+    fn value(&self) -> &Self::Value;
+
+    /// Get a mutable reference to the wrapped value.
+    fn value_mut(&mut self) -> &mut Self::Value;
+}
+
+impl<T, N: HasName> NamedElement for Named<T, N> {
+    type Value = T;
+    const NAME: &'static str = N::NAME;
+
+    fn value(&self) -> &T {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// Trait exposing the names of a tuple's [`Named`] elements at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct Age;
+/// impl HasName for Age {
+///     const NAME: &'static str = "age";
+/// }
+/// struct Nickname;
+/// impl HasName for Nickname {
+///     const NAME: &'static str = "nickname";
+/// }
+///
+/// type Profile = (Named<u8, Age>, Named<&'static str, Nickname>);
+/// assert_eq!(Profile::names(), &["age", "nickname"]);
+/// ```
+pub trait NamedTuple {
+    /// The names of every element, in tuple order.
+    fn names() -> &'static [&'static str];
+
+    /// The name of the element at `index`, if any.
+    #[inline]
+    fn name(index: usize) -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        Self::names().get(index).copied()
+    }
+}
+
+/// Trait for looking up a tuple's [`Named`] elements by their runtime name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct Age;
+/// impl HasName for Age {
+///     const NAME: &'static str = "age";
+/// }
+///
+/// let tuple = (Named::<_, Age>::new(27u8),);
+/// assert_eq!(tuple.match_name::<u8>("age"), Some(&27u8));
+/// assert_eq!(tuple.match_name::<u8>("height"), None);
+/// ```
+pub trait MatchName {
+    /// Get a reference to the element named `name`, if it exists and its
+    /// wrapped type unifies with `T`.
+    fn match_name<T: 'static>(&self, name: &str) -> Option<&T>;
+
+    /// Get a mutable reference to the element named `name`, if it exists
+    /// and its wrapped type unifies with `T`.
+    fn match_name_mut<T: 'static>(&mut self, name: &str) -> Option<&mut T>;
+}
+
+typed_tuple_macros::impl_named_tuple!(12);
@@ -0,0 +1,8 @@
+//! The crate prelude: a single glob import bringing every public trait and
+//! type into scope.
+//!
+//! ```rust
+//! use typed_tuple::prelude::*;
+//! ```
+
+pub use crate::*;
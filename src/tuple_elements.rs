@@ -0,0 +1,37 @@
+use crate::Homogeneous;
+
+/// ## TupleElements
+///
+/// [`TupleElements`] extends [`Homogeneous`] with element iteration, for
+/// tuples whose fields all share a single type `T`. It adds
+/// [`TupleElements::elements`] for walking the fields as an iterator; for an
+/// owned fixed-size array, go through [`Homogeneous::into_array`] directly.
+///
+/// The original request also asked for borrowing `as_array`/`as_mut_array`
+/// accessors; see [`Homogeneous`]'s trait-level scope note for why those
+/// aren't implemented.
+///
+/// ```rust
+/// # use typed_tuple::{Homogeneous, TupleElements};
+/// let tuple = (1u8, 2u8, 3u8);
+/// assert_eq!(tuple.elements().sum::<u8>(), 6u8);
+/// ```
+pub trait TupleElements<T>: Homogeneous<T> {
+    /// Consumes the tuple, returning an iterator over its elements in order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use typed_tuple::TupleElements;
+    /// let tuple = (1u8, 2u8, 3u8);
+    /// let elements: Vec<u8> = tuple.elements().collect();
+    /// assert_eq!(elements, vec![1, 2, 3]);
+    /// ```
+    fn elements(self) -> core::array::IntoIter<T, { Self::LEN }>
+    where
+        Self: Sized,
+    {
+        self.into_array().into_iter()
+    }
+}
+
+impl<T, TT: Homogeneous<T>> TupleElements<T> for TT {}
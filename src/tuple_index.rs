@@ -1,8 +1,17 @@
 //! Submodule defining the `TupleIndex` trait for tuple index types.
 
 use crate::prelude::*;
+use typed_tuple_macros::impl_tuple_index;
 
 /// Trait for tuple index types.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// assert_eq!(<TupleIndex0 as TupleIndex>::INDEX, 0);
+/// assert_eq!(<TupleIndex5 as TupleIndex>::INDEX, 5);
+/// ```
 pub trait TupleIndex:
     Sized
     + TupleIndexSub<Self, Output = TupleIndex0>
@@ -12,3 +21,5 @@ pub trait TupleIndex:
     /// The associated index value.
     const INDEX: usize;
 }
+
+impl_tuple_index!(64);
@@ -0,0 +1,26 @@
+//! Sub-module exposing tuple arity as a type-level length.
+
+use typed_tuple_macros::impl_tuple_len;
+
+/// Trait exposing the arity of a tuple as both a type-level
+/// [`typenum::Unsigned`] and a `const usize`, letting generic code branch on
+/// tuple size without destructuring.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type MyTuple = (u8, u16, u32);
+/// assert_eq!(<MyTuple as TupleLen>::LEN, 3);
+/// type MyLen = <MyTuple as TupleLen>::Len;
+/// assert_eq!(<MyLen as typenum::Unsigned>::to_usize(), 3);
+/// ```
+pub trait TupleLen {
+    /// The arity of the tuple as a type-level unsigned integer.
+    type Len: typenum::Unsigned;
+
+    /// The arity of the tuple as a `const usize`.
+    const LEN: usize;
+}
+
+impl_tuple_len!(12);
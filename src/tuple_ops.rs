@@ -0,0 +1,13 @@
+//! Element-wise `core::ops::{Add, Sub, Mul, Div}` for tuples of matching
+//! arity, where each corresponding pair of components implements the same
+//! operator.
+//!
+//! ```rust
+//! let sum = (1u32, 2.0f32) + (2u32, 3.0f32);
+//! assert_eq!(sum, (3u32, 5.0f32));
+//!
+//! let product = (3u32, 4.0f32) * (7u32, 3.0f32);
+//! assert_eq!(product, (21u32, 12.0f32));
+//! ```
+
+typed_tuple_macros::impl_tuple_ops!(12);
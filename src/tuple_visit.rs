@@ -0,0 +1,102 @@
+//! Per-type mapping/folding over every element of a tuple via [`Mapper`]/
+//! [`Folder`] implementations keyed on element type.
+//!
+//! See [`TypedVisit`](crate::TypedVisit)'s module docs for how this relates
+//! to [`TupleVisit`](crate::TupleVisit) and [`TypedVisit`](crate::TypedVisit),
+//! the other two "visit every element" traits in this crate, and which to
+//! reach for.
+
+use typed_tuple_macros::impl_tuple_visit;
+
+/// A polymorphic mapping operation, callable on any element type a tuple
+/// might contain.
+///
+/// Unlike a closure, `Mapper` can be implemented for several distinct `In`
+/// types on the same type, which is what lets [`TupleMap::map_each`] walk a
+/// heterogeneous tuple with a single value.
+///
+/// ```rust
+/// # use typed_tuple::{Mapper, TupleMap};
+/// struct Stringify;
+///
+/// impl Mapper<u8> for Stringify {
+///     type Out = String;
+///     fn map(&mut self, x: u8) -> String {
+///         x.to_string()
+///     }
+/// }
+///
+/// impl Mapper<bool> for Stringify {
+///     type Out = String;
+///     fn map(&mut self, x: bool) -> String {
+///         x.to_string()
+///     }
+/// }
+///
+/// let tuple = (1u8, true);
+/// assert_eq!(tuple.map_each(Stringify), ("1".to_string(), "true".to_string()));
+/// ```
+pub trait Mapper<In> {
+    /// The type produced for this element type.
+    type Out;
+
+    /// Maps a single element.
+    fn map(&mut self, x: In) -> Self::Out;
+}
+
+/// ## TupleMap
+///
+/// [`TupleMap`] applies a [`Mapper`] to every element of a tuple in order,
+/// producing a new tuple of the per-field [`Mapper::Out`] types. This
+/// generalizes [`TypedTuple::apply`]/[`TypedTuple::map`], which only ever
+/// touch the single element whose type the closure names.
+pub trait TupleMap<M> {
+    /// The tuple of per-field mapped outputs.
+    type Output;
+
+    /// Maps every element of the tuple with `m`, in order.
+    fn map_each(self, m: M) -> Self::Output;
+}
+
+/// A polymorphic folding operation, callable on any element type a tuple
+/// might contain.
+///
+/// Mirrors [`Mapper`], but threads an accumulator through each element
+/// instead of producing a per-element output.
+///
+/// ```rust
+/// # use typed_tuple::{Folder, TupleFold};
+/// struct Sum;
+///
+/// impl Folder<u32, u8> for Sum {
+///     fn fold(&mut self, acc: u32, x: u8) -> u32 {
+///         acc + x as u32
+///     }
+/// }
+///
+/// impl Folder<u32, u16> for Sum {
+///     fn fold(&mut self, acc: u32, x: u16) -> u32 {
+///         acc + x as u32
+///     }
+/// }
+///
+/// let tuple = (1u8, 2u16);
+/// assert_eq!(tuple.fold_each(0u32, Sum), 3u32);
+/// ```
+pub trait Folder<Acc, In> {
+    /// Folds a single element into the accumulator.
+    fn fold(&mut self, acc: Acc, x: In) -> Acc;
+}
+
+/// ## TupleFold
+///
+/// [`TupleFold`] left-folds a [`Folder`] across every element of a tuple in
+/// order, the way [`Iterator::fold`] folds across a homogeneous sequence.
+pub trait TupleFold: Sized {
+    /// Left-folds `f` across every element of the tuple, in order, starting
+    /// from `init`. Each concrete tuple arity requires `F` to implement
+    /// [`Folder`] for every one of its field types.
+    fn fold_each<Acc, F>(self, init: Acc, f: F) -> Acc;
+}
+
+impl_tuple_visit!(12);
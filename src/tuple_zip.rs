@@ -0,0 +1,29 @@
+use crate::{TypedUnzip, TypedZip};
+
+/// [`TupleZip`] is the requested name for the zip capability already
+/// provided by [`TypedZip`]; it is blanket-implemented for every type that
+/// implements [`TypedZip`] so callers can reach `zip` under either name.
+///
+/// ```rust
+/// # use typed_tuple::TupleZip;
+/// let tuple = (1u8, 2u16);
+/// let other = ("a", "b");
+/// assert_eq!(tuple.zip(other), ((1u8, "a"), (2u16, "b")));
+/// ```
+pub trait TupleZip<Other>: TypedZip<Other> {}
+
+impl<Other, TT: TypedZip<Other>> TupleZip<Other> for TT {}
+
+/// [`TupleUnzip`] is the requested name for the unzip capability already
+/// provided by [`TypedUnzip`]; see [`TupleZip`] for the analogous zip side.
+///
+/// ```rust
+/// # use typed_tuple::TupleUnzip;
+/// let zipped = ((1u8, "a"), (2u16, "b"));
+/// let (left, right) = zipped.unzip();
+/// assert_eq!(left, (1u8, 2u16));
+/// assert_eq!(right, ("a", "b"));
+/// ```
+pub trait TupleUnzip: TypedUnzip {}
+
+impl<TT: TypedUnzip> TupleUnzip for TT {}
@@ -1,4 +1,4 @@
-use typed_tuple_macros::impl_typed_bound;
+use typed_tuple_macros::{impl_typed_bound, impl_typed_join};
 
 /// ## TypedBound
 ///
@@ -72,3 +72,71 @@ pub trait TypedBound<const INDEX: usize, L, R>: Sized {
 }
 
 impl_typed_bound!(12);
+
+/// ## TypedJoin
+///
+/// [`TypedJoin`] is the inverse of [`TypedBound::split`]: it recombines a
+/// left and right tuple into a single tuple with the left tuple's elements
+/// followed by the right tuple's.
+///
+/// ```rust
+/// # use typed_tuple::TypedJoin;
+/// let left = (0u8, 1u16);
+/// let right = (2u32, 3u64);
+/// assert_eq!(left.join(right), (0u8, 1u16, 2u32, 3u64));
+/// ```
+pub trait TypedJoin<Rhs> {
+    /// The tuple type produced by joining `Self` and `Rhs`.
+    type Output;
+
+    /// Joins `self` with `rhs`, placing `self`'s elements first.
+    ///
+    /// # Example
+    /// ```
+    /// # use typed_tuple::TypedJoin;
+    /// let left = (0u8,);
+    /// let right = (1u16, 2u32);
+    /// assert_eq!(left.join(right), (0u8, 1u16, 2u32));
+    /// assert_eq!(().join(right), (1u16, 2u32));
+    /// assert_eq!(left.join(()), (0u8,));
+    /// ```
+    fn join(self, rhs: Rhs) -> Self::Output;
+
+    /// Appends a single `value` to the end of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use typed_tuple::TypedJoin;
+    /// let tuple = (0u8, 1u16);
+    /// assert_eq!(tuple.append(2u32), (0u8, 1u16, 2u32));
+    /// ```
+    #[inline]
+    fn append<T>(self, value: T) -> <Self as TypedJoin<(T,)>>::Output
+    where
+        Self: Sized + TypedJoin<(T,)>,
+    {
+        self.join((value,))
+    }
+}
+
+/// Prepends a single `value` to the front of `tuple`.
+///
+/// This is the single-element counterpart to [`TypedJoin::append`]; it is a
+/// free function rather than a trait method since the element being
+/// prepended is the receiver of `join`, not `tuple`.
+///
+/// # Example
+/// ```rust
+/// # use typed_tuple::{prepend, TypedJoin};
+/// let tuple = (1u16, 2u32);
+/// assert_eq!(prepend(0u8, tuple), (0u8, 1u16, 2u32));
+/// ```
+#[inline]
+pub fn prepend<T, TT>(value: T, tuple: TT) -> <(T,) as TypedJoin<TT>>::Output
+where
+    (T,): TypedJoin<TT>,
+{
+    (value,).join(tuple)
+}
+
+impl_typed_join!(12);
@@ -0,0 +1,38 @@
+//! Submodule providing the `TypedFromEnd` trait for accessing a tuple
+//! element relative to its end.
+
+use crate::prelude::*;
+use typed_tuple_macros::impl_typed_from_end;
+
+/// Trait for accessing the element `N` positions from the end of a tuple.
+///
+/// This trait is implemented for all tuple types (by default up to size 12)
+/// for valid indices `N`. An `N` that is not a valid index for the tuple's
+/// length simply has no implementation, so it fails to resolve at compile
+/// time rather than silently aliasing index 0.
+///
+/// `N` is a generic parameter of the trait itself, not of
+/// [`TypedFromEnd::get_from_end`], so it can't be turbofished on the method
+/// call; specify it through the trait as shown below, the same way
+/// [`TypedIndex`](crate::TypedIndex)'s `INDEX` is specified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// type MyTuple = (u8, u16, u32);
+/// let tuple: MyTuple = (1, 2, 3);
+/// let last: &u32 = <MyTuple as TypedFromEnd<typenum::U0>>::get_from_end(&tuple);
+/// assert_eq!(*last, 3);
+/// let second_last: &u16 = <MyTuple as TypedFromEnd<typenum::U1>>::get_from_end(&tuple);
+/// assert_eq!(*second_last, 2);
+/// ```
+pub trait TypedFromEnd<N: typenum::Unsigned>: TupleLen {
+    /// The type of the element `N` positions from the end.
+    type FromEndType;
+
+    /// Get a reference to the element `N` positions from the end.
+    fn get_from_end(&self) -> &Self::FromEndType;
+}
+
+impl_typed_from_end!(12);
@@ -0,0 +1,47 @@
+//! Submodule providing the `TypedInsert` trait for splicing a new element
+//! into a tuple at a type-level index.
+
+use crate::prelude::*;
+
+/// Trait for inserting an element into a tuple at a given index, producing a
+/// tuple with one additional element.
+///
+/// This is the inverse of [`TypedBounds::split_exclusive_at`]: splitting at
+/// `INDEX` decomposes a tuple into `(left, element, right)`, while inserting
+/// at `INDEX` recombines `left`, the new `value`, and the untouched
+/// `[INDEX..]` tail, shifting every later element one position to the right.
+pub trait TypedInsert<INDEX: TupleIndex, T> {
+    /// The type of the tuple after `value` has been inserted at `INDEX`.
+    type Output;
+
+    /// Inserts `value` at `INDEX`, shifting the element currently at `INDEX`
+    /// (and all elements after it) one position to the right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16, 4u32);
+    /// let inserted = tuple.insert_at::<TupleIndex2>(3u16);
+    /// assert_eq!(inserted, (1u8, 2u16, 3u16, 4u32));
+    /// ```
+    fn insert_at(self, value: T) -> Self::Output;
+}
+
+impl<INDEX, E, T, TT> TypedInsert<INDEX, T> for TT
+where
+    INDEX: TupleIndex,
+    TT: TypedBounds<INDEX, E>,
+    TT::SplitLeftExclusive: ChainRight<(T,)>,
+    <TT::SplitLeftExclusive as ChainRight<(T,)>>::Output: ChainRight<TT::SplitRightInclusive>,
+{
+    type Output =
+        <<TT::SplitLeftExclusive as ChainRight<(T,)>>::Output as ChainRight<
+            TT::SplitRightInclusive,
+        >>::Output;
+
+    fn insert_at(self, value: T) -> Self::Output {
+        let (left, right) = TypedTuple::<E>::split_right::<INDEX>(self);
+        left.chain_right((value,)).chain_right(right)
+    }
+}
@@ -0,0 +1,23 @@
+use typed_tuple_macros::impl_typed_len;
+
+/// Trait exposing the arity of a tuple as a `const usize`, matching the
+/// `HasConstLen` const-length trait from the `libafl_bolts` tuples module.
+///
+/// Unlike `TupleLen`, which also exposes the arity as a type-level
+/// `typenum::Unsigned` for arithmetic composition, `TypedLen` is the plain
+/// value-only counterpart, generated the same way as [`TypedIndex`] and the
+/// other old-style tuple traits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::TypedLen;
+/// let tuple = (0u8, 1u16, 2u32);
+/// assert_eq!(<(u8, u16, u32) as TypedLen>::LEN, 3);
+/// ```
+pub trait TypedLen {
+    /// The arity of the tuple.
+    const LEN: usize;
+}
+
+impl_typed_len!(12);
@@ -0,0 +1,41 @@
+//! Submodule providing the `TypedMatchType` trait for looking up a tuple
+//! element by its type rather than its position.
+
+use typed_tuple_macros::impl_typed_match_type;
+
+/// Trait for retrieving a reference to the first element whose type
+/// unifies with the requested `T`, where [`TypedIndex`](crate::TypedIndex)
+/// requires the caller to already know (or infer) the unique position.
+///
+/// Elements are compared using [`core::any::Any`], so every element type
+/// must be `'static`. This makes `match_first_type` useful when the caller
+/// knows the type they want but not its index, and tolerates tuples that
+/// contain more than one element of a given type (the *first* one wins).
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::TypedMatchType;
+/// let tuple = (1u8, "hello", 2u32, 3u32);
+/// assert_eq!(tuple.match_first_type::<u32>(), Some(&2u32));
+/// assert_eq!(tuple.match_first_type::<u64>(), None);
+/// ```
+pub trait TypedMatchType {
+    /// Get a reference to the first element of type `T`, or `None` if the
+    /// tuple has no element of that type.
+    fn match_first_type<T: 'static>(&self) -> Option<&T>;
+
+    /// Get a mutable reference to the first element of type `T`, or `None`
+    /// if the tuple has no element of that type.
+    ///
+    /// # Example
+    /// ```
+    /// # use typed_tuple::TypedMatchType;
+    /// let mut tuple = (1u8, "hello", 2u32);
+    /// *tuple.match_first_type_mut::<u32>().unwrap() = 42;
+    /// assert_eq!(tuple, (1u8, "hello", 42u32));
+    /// ```
+    fn match_first_type_mut<T: 'static>(&mut self) -> Option<&mut T>;
+}
+
+impl_typed_match_type!(12);
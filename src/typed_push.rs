@@ -0,0 +1,126 @@
+//! Submodule providing `PushFront`/`PushBack`/`PopFront`/`PopBack` end
+//! operations for tuples.
+
+use crate::prelude::*;
+
+/// Trait for prepending an element to the front of a tuple.
+pub trait PushFront<T> {
+    /// The type of the tuple after `value` has been prepended.
+    type Output;
+
+    /// Prepends `value`, shifting every existing element one position to
+    /// the right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (2u16, 3u32);
+    /// assert_eq!(tuple.push_front(1u8), (1u8, 2u16, 3u32));
+    /// assert_eq!(().push_front(1u8), (1u8,));
+    /// ```
+    fn push_front(self, value: T) -> Self::Output;
+}
+
+impl<T, TT> PushFront<T> for TT
+where
+    (T,): ChainRight<TT>,
+{
+    type Output = <(T,) as ChainRight<TT>>::Output;
+
+    fn push_front(self, value: T) -> Self::Output {
+        (value,).chain_right(self)
+    }
+}
+
+/// Trait for appending an element to the back of a tuple.
+pub trait PushBack<T> {
+    /// The type of the tuple after `value` has been appended.
+    type Output;
+
+    /// Appends `value` as the new last element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16);
+    /// assert_eq!(tuple.push_back(3u32), (1u8, 2u16, 3u32));
+    /// assert_eq!(().push_back(1u8), (1u8,));
+    /// ```
+    fn push_back(self, value: T) -> Self::Output;
+}
+
+impl<T, TT> PushBack<T> for TT
+where
+    TT: ChainRight<(T,)>,
+{
+    type Output = <TT as ChainRight<(T,)>>::Output;
+
+    fn push_back(self, value: T) -> Self::Output {
+        self.chain_right((value,))
+    }
+}
+
+/// Trait for removing and returning the first element of a tuple.
+pub trait PopFront<T> {
+    /// The type of the remaining tuple.
+    type Output;
+
+    /// Removes the first element, returning it along with the remaining
+    /// tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16, 3u32);
+    /// let (first, rest) = tuple.pop_front();
+    /// assert_eq!(first, 1u8);
+    /// assert_eq!(rest, (2u16, 3u32));
+    /// ```
+    fn pop_front(self) -> (T, Self::Output);
+}
+
+impl<T, TT> PopFront<T> for TT
+where
+    TT: TypedBounds<TupleIndex0, T>,
+{
+    type Output = TT::PopOutput;
+
+    fn pop_front(self) -> (T, Self::Output) {
+        TypedTuple::<T>::pop::<TupleIndex0>(self)
+    }
+}
+
+/// Trait for removing and returning the last element of a tuple.
+pub trait PopBack<T> {
+    /// The type of the remaining tuple.
+    type Output;
+
+    /// Removes the last element, returning it along with the remaining
+    /// tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16, 3u32);
+    /// let (last, rest) = tuple.pop_back();
+    /// assert_eq!(last, 3u32);
+    /// assert_eq!(rest, (1u8, 2u16));
+    /// ```
+    fn pop_back(self) -> (T, Self::Output);
+}
+
+impl<T, TT> PopBack<T> for TT
+where
+    TT: LastIndex,
+    TT: TypedBounds<<TT as LastIndex>::Last, T>,
+{
+    type Output = TT::PopOutput;
+
+    fn pop_back(self) -> (T, Self::Output) {
+        TypedTuple::<T>::pop::<<TT as LastIndex>::Last>(self)
+    }
+}
@@ -0,0 +1,32 @@
+use typed_tuple_macros::impl_typed_reverse;
+
+/// Trait for reversing the element order of a tuple.
+///
+/// [`TypedReverse::reverse`] consumes `Self` returning the element-reversed
+/// tuple, borrows `&Self`, returning a tuple of element references in
+/// reversed order, and mutably borrows `&mut Self`, returning a tuple of
+/// mutable element references in reversed order, following the same
+/// owned/`&`/`&mut` pattern as [`TypedExtract`](crate::TypedExtract).
+///
+/// Composes naturally with the existing split/pop machinery: front-oriented
+/// algorithms can operate on the tail of a tuple by reversing first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::TypedReverse;
+/// let tuple = (0u8, 1u16, 2u32);
+/// assert_eq!(tuple.reverse(), (2u32, 1u16, 0u8));
+///
+/// let tuple = (0u8, 1u16, 2u32);
+/// assert_eq!((&tuple).reverse(), (&2u32, &1u16, &0u8));
+/// ```
+pub trait TypedReverse {
+    /// The element-reversed tuple type.
+    type Output;
+
+    /// Reverse the element order of the tuple.
+    fn reverse(self) -> Self::Output;
+}
+
+impl_typed_reverse!(12);
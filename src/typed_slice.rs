@@ -0,0 +1,40 @@
+use typed_tuple_macros::impl_typed_slice;
+
+/// ## TypedSlice
+///
+/// [`TypedSlice`] pulls a contiguous, `[START, END)` middle segment out of a
+/// tuple, the way range indexing does on [`core::slice`]. Each
+/// `(START, END)` impl is generated directly, picking the fields of each
+/// segment by position; the result is the same as splitting the tuple at
+/// `START` with [`TypedSplit`](crate::TypedSplit) giving `(L, rest)`, then
+/// splitting `rest` at `END - START` giving `(M, R)`.
+///
+/// [`TypedSlice::slice`] consumes `Self` returning the three owned segments,
+/// borrows `&Self` returning reference tuples, and mutably borrows
+/// `&mut Self` returning mutable reference tuples.
+///
+/// ```rust
+/// # use typed_tuple::TypedSlice;
+/// let tuple = (0u8, 1u16, 2u32, 3u64, 4u128);
+/// let (_, mid, _): (_, (u16, u32), _) = tuple.slice();
+/// assert_eq!(mid, (1, 2));
+/// ```
+pub trait TypedSlice<const START: usize, const END: usize, L, M, R>: Sized {
+    /// Split a tuple into `[0, START)`, `[START, END)`, and `[END, ..)`
+    /// segments. The middle segment is inferred from the `M` type pattern,
+    /// matching [`TypedSplit`](crate::TypedSplit)'s type-inference style.
+    ///
+    /// # Example
+    /// ```
+    /// # use typed_tuple::TypedSlice;
+    /// let tuple = (0u8, 1u16, 2u32, 3u64, 4u128);
+    ///
+    /// let (left, mid, right): (_, (u16, u32), _) = tuple.slice();
+    /// assert_eq!(left, (0,));
+    /// assert_eq!(mid, (1, 2));
+    /// assert_eq!(right, (3, 4));
+    /// ```
+    fn slice(self) -> (L, M, R);
+}
+
+impl_typed_slice!(12);
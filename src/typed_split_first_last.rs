@@ -0,0 +1,70 @@
+//! Submodule providing the `TypedSplitFirst`/`TypedSplitLast` head/tail
+//! decomposition traits.
+
+use crate::prelude::*;
+
+/// Peels the first element off the front of a tuple, returning it along
+/// with the remaining tuple — the tuple analogue of [`slice::split_first`].
+///
+/// [`TypedSplitFirst`] is the requested name for the decomposition already
+/// provided by [`PopFront`]; it is blanket-implemented for every type that
+/// implements [`PopFront`] so callers can reach it under either name. For
+/// borrowed (`&self`/`&mut self`) head/tail access, split at index `1` with
+/// [`TypedSplit`] instead, which generates the reference-returning variants
+/// directly.
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// let tuple = (1u8, 2u16, 3u32);
+/// let (head, tail) = tuple.split_first();
+/// assert_eq!(head, 1u8);
+/// assert_eq!(tail, (2u16, 3u32));
+/// ```
+pub trait TypedSplitFirst<T>: PopFront<T> {
+    /// The type of the tuple without its first element.
+    type Tail;
+
+    /// Removes and returns the first element, along with the remaining
+    /// tuple.
+    fn split_first(self) -> (T, Self::Tail);
+}
+
+impl<T, TT: PopFront<T>> TypedSplitFirst<T> for TT {
+    type Tail = TT::Output;
+
+    fn split_first(self) -> (T, Self::Tail) {
+        self.pop_front()
+    }
+}
+
+/// Peels the last element off the back of a tuple, returning the remaining
+/// tuple along with it — the tuple analogue of [`slice::split_last`].
+///
+/// [`TypedSplitLast`] is the requested name for the decomposition already
+/// provided by [`PopBack`]; see [`TypedSplitFirst`] for the analogous head
+/// side, including the note on borrowed access via [`TypedSplit`].
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// let tuple = (1u8, 2u16, 3u32);
+/// let (init, last) = tuple.split_last();
+/// assert_eq!(init, (1u8, 2u16));
+/// assert_eq!(last, 3u32);
+/// ```
+pub trait TypedSplitLast<T>: PopBack<T> {
+    /// The type of the tuple without its last element.
+    type Init;
+
+    /// Removes and returns the last element, along with the remaining
+    /// tuple, in `(Init, Last)` order.
+    fn split_last(self) -> (Self::Init, T);
+}
+
+impl<T, TT: PopBack<T>> TypedSplitLast<T> for TT {
+    type Init = TT::Output;
+
+    fn split_last(self) -> (Self::Init, T) {
+        let (last, init) = self.pop_back();
+        (init, last)
+    }
+}
@@ -0,0 +1,58 @@
+//! Submodule providing the `TypedSwap` trait for exchanging two elements
+//! (and their types) of a tuple.
+
+use crate::prelude::*;
+
+/// Trait for exchanging the elements at `I` and `J` (with `I < J`),
+/// producing a tuple with the two element *types* swapped in the output
+/// type, not just their values.
+///
+/// Built on [`IndexedTuple::split_exclusive_at`]: the tuple is split
+/// exclusively at `I` into `left = [0, I)`, `elem_i`, and the remainder;
+/// the remainder is then split exclusively at `J` (rebased into the
+/// remainder's own coordinates) into `mid = (I, J)`, `elem_j`, and
+/// `right = (J, ..)`. The pieces are then reassembled in swapped order as
+/// `left ++ (elem_j,) ++ mid ++ (elem_i,) ++ right` through the existing
+/// [`ChainRight`] machinery.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// let tuple = (1u8, 2u16, 3u32, 4u64, 5i8);
+/// let swapped = TypedSwap::<TupleIndex1, TupleIndex3, _, _>::swap(tuple);
+/// assert_eq!(swapped, (1u8, 4u64, 3u32, 2u16, 5i8));
+/// ```
+pub trait TypedSwap<I: TupleIndex, J: TupleIndex, T, U> {
+    /// The tuple type with the elements (and types) at `I` and `J` swapped.
+    type Output;
+
+    /// Swap the elements at `I` and `J`, consuming `self`.
+    fn swap(self) -> Self::Output;
+}
+
+impl<I, J, JMinusI, JMid, T, U, TT, Rest, Mid, Right, Step1, Step2, Step3> TypedSwap<I, J, T, U>
+    for TT
+where
+    I: TupleIndex,
+    J: TupleIndex + TupleIndexSub<I, Output = JMinusI>,
+    JMinusI: TupleIndexSub<TupleIndex1, Output = JMid>,
+    JMid: TupleIndex,
+    TT: IndexedTuple<I, T, SplitRightExclusive = Rest>,
+    Rest: IndexedTuple<JMid, U, SplitLeftExclusive = Mid, SplitRightExclusive = Right>,
+    TT::SplitLeftExclusive: ChainRight<(U,), Output = Step1>,
+    Step1: ChainRight<Mid, Output = Step2>,
+    Step2: ChainRight<(T,), Output = Step3>,
+    Step3: ChainRight<Right>,
+{
+    type Output = <Step3 as ChainRight<Right>>::Output;
+
+    fn swap(self) -> Self::Output {
+        let (left, elem_i, rest) = IndexedTuple::<I, T>::split_exclusive_at(self);
+        let (mid, elem_j, right) = IndexedTuple::<JMid, U>::split_exclusive_at(rest);
+        left.chain_right((elem_j,))
+            .chain_right(mid)
+            .chain_right((elem_i,))
+            .chain_right(right)
+    }
+}
@@ -113,6 +113,93 @@ pub trait TypedTupleExt<T>: Sized {
     {
         <Self as TypedTuple<Idx, T>>::split_inclusive(self)
     }
+
+    #[inline]
+    /// Prepends `value` to the front of the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (2u16, 3u32);
+    /// assert_eq!(tuple.push_front(1u8), (1u8, 2u16, 3u32));
+    /// ```
+    fn push_front(self, value: T) -> Self::Output
+    where
+        Self: PushFront<T>,
+    {
+        PushFront::push_front(self, value)
+    }
+
+    #[inline]
+    /// Appends `value` to the back of the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16);
+    /// assert_eq!(tuple.push_back(3u32), (1u8, 2u16, 3u32));
+    /// ```
+    fn push_back(self, value: T) -> Self::Output
+    where
+        Self: PushBack<T>,
+    {
+        PushBack::push_back(self, value)
+    }
+
+    #[inline]
+    /// Removes and returns the first element of the tuple, along with the
+    /// remaining tuple.
+    fn pop_front(self) -> (T, Self::Output)
+    where
+        Self: PopFront<T>,
+    {
+        PopFront::pop_front(self)
+    }
+
+    #[inline]
+    /// Removes and returns the last element of the tuple, along with the
+    /// remaining tuple.
+    fn pop_back(self) -> (T, Self::Output)
+    where
+        Self: PopBack<T>,
+    {
+        PopBack::pop_back(self)
+    }
+
+    #[inline]
+    /// Inserts `value` at `Idx`, shifting the element currently at `Idx`
+    /// (and all elements after it) one position to the right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// let tuple = (1u8, 2u16, 4u32);
+    /// assert_eq!(tuple.insert_at::<TupleIndex2>(3u16), (1u8, 2u16, 3u16, 4u32));
+    /// ```
+    fn insert_at<Idx>(self, value: T) -> Self::Output
+    where
+        Idx: TupleIndex,
+        Self: TypedInsert<Idx, T>,
+    {
+        TypedInsert::<Idx, T>::insert_at(self, value)
+    }
+
+    #[inline]
+    /// Removes the element of type `T` at `Idx`, returning it along with the
+    /// remaining tuple.
+    ///
+    /// This is equivalent to [`TypedTupleExt::pop_at`], named to match
+    /// [`TypedTupleExt::insert_at`] as its inverse operation.
+    fn remove_at<Idx>(self) -> (T, Self::PopOutput)
+    where
+        Idx: TupleIndex,
+        Self: TypedTuple<Idx, T>,
+    {
+        self.pop_at::<Idx>()
+    }
 }
 
 impl<T, TT> TypedTupleExt<T> for TT {}
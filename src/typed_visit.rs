@@ -0,0 +1,136 @@
+//! Submodule for a polymorphic visitor subsystem mapping/folding over every
+//! heterogeneous element of a tuple.
+//!
+//! This builds directly on [`ElementVisitor`] and [`TupleVisit`]: [`VisitMut`]
+//! is simply the name this request uses for the already-existing mutable
+//! element visitor, and [`TypedVisit::for_each_mut`] redirects straight to
+//! [`TupleVisit::for_each`]. [`Visitor`] (read-only) and [`MapVisitor`]
+//! (value-consuming) are the genuinely new capabilities added here.
+//!
+//! ## Which whole-tuple traversal should I use?
+//!
+//! Three separate requests independently asked for "apply one operation to
+//! every element of a tuple" and landed three similarly-shaped traits. They
+//! are not interchangeable; pick based on whether per-element types need
+//! their own `impl`, and whether you're reading, mutating, or replacing:
+//!
+//! - [`TupleMap`](crate::TupleMap)/[`TupleFold`](crate::TupleFold) (in
+//!   `tuple_visit`): the operation is a [`Mapper`](crate::Mapper)/
+//!   [`Folder`](crate::Folder) with a dedicated `impl` per element type it
+//!   needs to handle. Reach for these when the per-type behavior genuinely
+//!   differs (e.g. formatting `u8` and `bool` differently).
+//! - [`TupleVisit`] (in `element_visit`): the visitor is a single `impl`
+//!   generic over `T`, reused unchanged across every element type. Reach
+//!   for this (via [`ElementVisitor`]/[`ElementFolder`]) when the same
+//!   logic applies to every element regardless of its type (e.g.
+//!   `T: Default` reset, or counting elements).
+//! - [`TypedVisit`] (this module): the same generic-visitor shape as
+//!   `TupleVisit`, but as a single trait covering read-only ([`Visitor`]),
+//!   in-place ([`VisitMut`], a [`ElementVisitor`] alias), and
+//!   type-changing ([`MapVisitor`]) traversal together, so a type that
+//!   needs more than one of those doesn't have to pull in both
+//!   `tuple_visit` and `element_visit`.
+//!
+//! New code that just needs one kind of traversal should prefer whichever
+//! of `TupleVisit`/`TypedVisit` already covers it rather than implementing
+//! both; `TupleMap`/`TupleFold` remain the right choice only when the
+//! operation is genuinely per-type.
+
+use crate::prelude::*;
+
+/// A visitor that reads every element of a tuple without mutating it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct Counter(usize);
+///
+/// impl Visitor for Counter {
+///     fn visit<T>(&mut self, _el: &T) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let tuple = (1u8, "hi", 2.5f32);
+/// let mut counter = Counter(0);
+/// tuple.for_each(&mut counter);
+/// assert_eq!(counter.0, 3);
+/// ```
+pub trait Visitor {
+    /// Visit a single element by shared reference.
+    fn visit<T>(&mut self, el: &T);
+}
+
+/// [`VisitMut`] is the requested name for the in-place mutation already
+/// provided by [`ElementVisitor`]; it is blanket-implemented for every
+/// `ElementVisitor` so callers can reach it under either name.
+pub trait VisitMut: ElementVisitor {}
+
+impl<V: ElementVisitor> VisitMut for V {}
+
+/// A visitor that consumes each element and produces a (possibly
+/// differently-typed) replacement for it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use typed_tuple::prelude::*;
+/// struct ToString;
+///
+/// impl MapVisitor for ToString {
+///     type Out<T> = String where T: core::fmt::Debug;
+///
+///     fn map<T: core::fmt::Debug>(&mut self, el: T) -> String {
+///         format!("{el:?}")
+///     }
+/// }
+/// ```
+pub trait MapVisitor {
+    /// The type produced from an element of type `T`.
+    type Out<T>;
+
+    /// Consume a single element, producing its replacement.
+    fn map<T>(&mut self, el: T) -> Self::Out<T>;
+}
+
+/// Trait exposing whole-tuple traversal and transformation via the
+/// [`Visitor`], [`VisitMut`], and [`MapVisitor`] traits.
+pub trait TypedVisit {
+    /// The tuple produced by mapping every element through `V`.
+    type MapOutput<V: MapVisitor>;
+
+    /// Visit every element of the tuple, in order, by shared reference.
+    fn for_each<V: Visitor>(&self, v: &mut V);
+
+    /// Visit every element of the tuple, in order, by mutable reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use typed_tuple::prelude::*;
+    /// struct ResetToDefault;
+    ///
+    /// impl ElementVisitor for ResetToDefault {
+    ///     fn visit<T: Default>(&mut self, el: &mut T) {
+    ///         *el = T::default();
+    ///     }
+    /// }
+    ///
+    /// let mut tuple = (1u8, 2u16, 3u32);
+    /// tuple.for_each_mut(&mut ResetToDefault);
+    /// assert_eq!(tuple, (0u8, 0u16, 0u32));
+    /// ```
+    #[inline]
+    fn for_each_mut<V: ElementVisitor>(&mut self, v: &mut V)
+    where
+        Self: TupleVisit,
+    {
+        TupleVisit::for_each(self, v)
+    }
+
+    /// Consume the tuple, mapping every element through `v`.
+    fn map_all<V: MapVisitor>(self, v: &mut V) -> Self::MapOutput<V>;
+}
+
+typed_tuple_macros::impl_typed_visit!(12);
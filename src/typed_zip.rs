@@ -0,0 +1,45 @@
+use typed_tuple_macros::impl_typed_zip;
+
+/// ## TypedZip
+///
+/// [`TypedZip`] combines two equal-length tuples element-wise into a tuple
+/// of pairs, the way [`Iterator::zip`] pairs up sequences.
+///
+/// ```rust
+/// # use typed_tuple::TypedZip;
+/// let tuple = (1u8, 2u16, 3u32);
+/// let other = ("a", "b", "c");
+/// let zipped = tuple.zip(other);
+/// assert_eq!(zipped, ((1u8, "a"), (2u16, "b"), (3u32, "c")));
+/// ```
+pub trait TypedZip<Other> {
+    /// The resulting tuple of `(Self, Other)` element pairs.
+    type Output;
+
+    /// Zips `self` and `other` into a tuple of element pairs.
+    fn zip(self, other: Other) -> Self::Output;
+}
+
+/// ## TypedUnzip
+///
+/// [`TypedUnzip`] is the inverse of [`TypedZip`]: it splits a tuple of pairs
+/// back into a pair of tuples.
+///
+/// ```rust
+/// # use typed_tuple::TypedUnzip;
+/// let zipped = ((1u8, "a"), (2u16, "b"), (3u32, "c"));
+/// let (left, right) = zipped.unzip();
+/// assert_eq!(left, (1u8, 2u16, 3u32));
+/// assert_eq!(right, ("a", "b", "c"));
+/// ```
+pub trait TypedUnzip {
+    /// The tuple of left-hand elements.
+    type Left;
+    /// The tuple of right-hand elements.
+    type Right;
+
+    /// Splits a tuple of pairs into a pair of tuples.
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl_typed_zip!(12);
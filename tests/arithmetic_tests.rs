@@ -0,0 +1,51 @@
+//! Tests for the `TupleIndexMul`/`TupleIndexMin`/`TupleIndexMax`/
+//! `TupleIndexLt`/`TupleIndexLe`/`TupleIndexEq`/`FromIndex` arithmetic
+//! traits.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_index_mul() {
+    type Result1 = <TupleIndex2 as TupleIndexMul<TupleIndex3>>::Output;
+    assert_eq!(<Result1 as TupleIndex>::INDEX, 6);
+
+    type Result2 = <TupleIndex0 as TupleIndexMul<TupleIndex10>>::Output;
+    assert_eq!(<Result2 as TupleIndex>::INDEX, 0);
+}
+
+#[test]
+fn test_index_min_max() {
+    type Min = <TupleIndex2 as TupleIndexMin<TupleIndex5>>::Output;
+    assert_eq!(<Min as TupleIndex>::INDEX, 2);
+
+    type Max = <TupleIndex2 as TupleIndexMax<TupleIndex5>>::Output;
+    assert_eq!(<Max as TupleIndex>::INDEX, 5);
+
+    // Symmetric: the larger operand on the left gives the same result.
+    type MinRev = <TupleIndex5 as TupleIndexMin<TupleIndex2>>::Output;
+    assert_eq!(<MinRev as TupleIndex>::INDEX, 2);
+}
+
+#[test]
+fn test_index_comparisons() {
+    type Lt = <TupleIndex2 as TupleIndexLt<TupleIndex5>>::Output;
+    let _: Lt = TrueIndex;
+    type Ge = <TupleIndex5 as TupleIndexLt<TupleIndex2>>::Output;
+    let _: Ge = FalseIndex;
+
+    type Le = <TupleIndex5 as TupleIndexLe<TupleIndex5>>::Output;
+    let _: Le = TrueIndex;
+    type Gt = <TupleIndex6 as TupleIndexLe<TupleIndex5>>::Output;
+    let _: Gt = FalseIndex;
+
+    type Eq = <TupleIndex5 as TupleIndexEq<TupleIndex5>>::Output;
+    let _: Eq = TrueIndex;
+    type Ne = <TupleIndex2 as TupleIndexEq<TupleIndex5>>::Output;
+    let _: Ne = FalseIndex;
+}
+
+#[test]
+fn test_from_index() {
+    type Five = <() as FromIndex<5>>::Output;
+    assert_eq!(<Five as TupleIndex>::INDEX, 5);
+}
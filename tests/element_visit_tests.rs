@@ -0,0 +1,49 @@
+use typed_tuple::prelude::*;
+
+struct ResetToDefault;
+
+impl ElementVisitor for ResetToDefault {
+    fn visit<T: Default>(&mut self, el: &mut T) {
+        *el = T::default();
+    }
+}
+
+struct CountElements;
+
+impl ElementFolder<usize> for CountElements {
+    fn fold<T>(&mut self, acc: usize, _el: &T) -> usize {
+        acc + 1
+    }
+}
+
+#[test]
+fn test_for_each_resets_every_field() {
+    let mut tuple = (1u8, 2u16, 3u32);
+    tuple.for_each(&mut ResetToDefault);
+    assert_eq!(tuple, (0u8, 0u16, 0u32));
+}
+
+#[test]
+fn test_for_each_mixed_types() {
+    let mut tuple = (1u8, "hi".to_string(), 2.5f32);
+    tuple.for_each(&mut ResetToDefault);
+    assert_eq!(tuple, (0u8, String::new(), 0.0f32));
+}
+
+#[test]
+fn test_fold_counts_elements() {
+    let tuple = (1u8, "hi", 2.5f32, 4u64);
+    assert_eq!(tuple.fold(&mut CountElements, 0), 4);
+}
+
+#[test]
+fn test_fold_single_element() {
+    let tuple = (42u8,);
+    assert_eq!(tuple.fold(&mut CountElements, 0), 1);
+}
+
+#[test]
+fn test_fold_empty_tuple() {
+    let tuple = ();
+    assert_eq!(tuple.fold(&mut CountElements, 0), 0);
+}
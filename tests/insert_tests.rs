@@ -0,0 +1,38 @@
+//! Tests for the `TypedInsert` trait.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_insert_middle() {
+    let tuple = (1u8, 2u16, 4u32);
+    let inserted = tuple.insert_at::<TupleIndex2>(3u16);
+    assert_eq!(inserted, (1u8, 2u16, 3u16, 4u32));
+}
+
+#[test]
+fn test_insert_front() {
+    let tuple = (2u16, 3u32);
+    let inserted = tuple.insert_at::<TupleIndex0>(1u8);
+    assert_eq!(inserted, (1u8, 2u16, 3u32));
+}
+
+#[test]
+fn test_insert_back() {
+    let tuple = (1u8, 2u16);
+    let inserted = tuple.insert_at::<TupleIndex2>(3u32);
+    assert_eq!(inserted, (1u8, 2u16, 3u32));
+}
+
+#[test]
+fn test_insert_into_single_element() {
+    let tuple = (2u16,);
+    let inserted = tuple.insert_at::<TupleIndex0>(1u8);
+    assert_eq!(inserted, (1u8, 2u16));
+}
+
+#[test]
+fn test_insert_into_empty() {
+    let tuple = ();
+    let inserted = tuple.insert_at::<TupleIndex0>(1u8);
+    assert_eq!(inserted, (1u8,));
+}
@@ -0,0 +1,26 @@
+use typed_tuple::TypedMatchType;
+
+#[test]
+fn test_match_first_type_found() {
+    let tuple = (1u8, "hello", 2u32, 3u32);
+    assert_eq!(tuple.match_first_type::<u32>(), Some(&2u32));
+}
+
+#[test]
+fn test_match_first_type_not_found() {
+    let tuple = (1u8, "hello", 2u32);
+    assert_eq!(tuple.match_first_type::<u64>(), None);
+}
+
+#[test]
+fn test_match_first_type_mut() {
+    let mut tuple = (1u8, "hello", 2u32);
+    *tuple.match_first_type_mut::<u32>().unwrap() = 42;
+    assert_eq!(tuple, (1u8, "hello", 42u32));
+}
+
+#[test]
+fn test_match_first_type_empty_tuple() {
+    let tuple = ();
+    assert_eq!(tuple.match_first_type::<u32>(), None);
+}
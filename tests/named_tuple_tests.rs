@@ -0,0 +1,41 @@
+//! Tests for `NamedTuple`/`MatchName`.
+
+use typed_tuple::prelude::*;
+
+struct Age;
+impl HasName for Age {
+    const NAME: &'static str = "age";
+}
+
+struct Nickname;
+impl HasName for Nickname {
+    const NAME: &'static str = "nickname";
+}
+
+type Profile = (Named<u8, Age>, Named<&'static str, Nickname>);
+
+#[test]
+fn test_names() {
+    assert_eq!(Profile::names(), &["age", "nickname"]);
+    assert_eq!(Profile::name(0), Some("age"));
+    assert_eq!(Profile::name(1), Some("nickname"));
+    assert_eq!(Profile::name(2), None);
+}
+
+#[test]
+fn test_match_name() {
+    let tuple: Profile = (Named::new(27u8), Named::new("robin"));
+    assert_eq!(tuple.match_name::<u8>("age"), Some(&27u8));
+    assert_eq!(tuple.match_name::<&str>("nickname"), Some(&"robin"));
+    assert_eq!(tuple.match_name::<u8>("height"), None);
+    assert_eq!(tuple.match_name::<&str>("age"), None);
+}
+
+#[test]
+fn test_match_name_mut() {
+    let mut tuple: Profile = (Named::new(27u8), Named::new("robin"));
+    if let Some(age) = tuple.match_name_mut::<u8>("age") {
+        *age += 1;
+    }
+    assert_eq!(*tuple.0.value(), 28u8);
+}
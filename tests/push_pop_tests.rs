@@ -0,0 +1,70 @@
+//! Tests for the `PushFront`/`PushBack`/`PopFront`/`PopBack` end operations.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_push_front() {
+    let tuple = (2u16, 3u32);
+    assert_eq!(tuple.push_front(1u8), (1u8, 2u16, 3u32));
+}
+
+#[test]
+fn test_push_front_empty() {
+    assert_eq!(().push_front(1u8), (1u8,));
+}
+
+#[test]
+fn test_push_back() {
+    let tuple = (1u8, 2u16);
+    assert_eq!(tuple.push_back(3u32), (1u8, 2u16, 3u32));
+}
+
+#[test]
+fn test_push_back_empty() {
+    assert_eq!(().push_back(1u8), (1u8,));
+}
+
+#[test]
+fn test_pop_front() {
+    let tuple = (1u8, 2u16, 3u32);
+    let (first, rest) = tuple.pop_front();
+    assert_eq!(first, 1u8);
+    assert_eq!(rest, (2u16, 3u32));
+}
+
+#[test]
+fn test_pop_back() {
+    let tuple = (1u8, 2u16, 3u32);
+    let (last, rest) = tuple.pop_back();
+    assert_eq!(last, 3u32);
+    assert_eq!(rest, (1u8, 2u16));
+}
+
+#[test]
+fn test_pop_front_single_element() {
+    let tuple = (1u8,);
+    let (first, rest) = tuple.pop_front();
+    assert_eq!(first, 1u8);
+    assert_eq!(rest, ());
+}
+
+#[test]
+fn test_pop_back_single_element() {
+    let tuple = (1u8,);
+    let (last, rest) = tuple.pop_back();
+    assert_eq!(last, 1u8);
+    assert_eq!(rest, ());
+}
+
+#[test]
+fn test_push_pop_roundtrip() {
+    let tuple = (1u8, 2u16, 3u32);
+    let pushed = tuple.push_front(0u8).push_back(4u64);
+    assert_eq!(pushed, (0u8, 1u8, 2u16, 3u32, 4u64));
+
+    let (last, rest) = pushed.pop_back();
+    assert_eq!(last, 4u64);
+    let (first, rest) = rest.pop_front();
+    assert_eq!(first, 0u8);
+    assert_eq!(rest, (1u8, 2u16, 3u32));
+}
@@ -0,0 +1,27 @@
+//! Tests for `TypedSplitFirst`/`TypedSplitLast`.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_split_first() {
+    let tuple = (1u8, 2u16, 3u32);
+    let (head, tail) = tuple.split_first();
+    assert_eq!(head, 1u8);
+    assert_eq!(tail, (2u16, 3u32));
+}
+
+#[test]
+fn test_split_last() {
+    let tuple = (1u8, 2u16, 3u32);
+    let (init, last) = tuple.split_last();
+    assert_eq!(init, (1u8, 2u16));
+    assert_eq!(last, 3u32);
+}
+
+#[test]
+fn test_split_first_single() {
+    let tuple = (1u8,);
+    let (head, tail) = tuple.split_first();
+    assert_eq!(head, 1u8);
+    assert_eq!(tail, ());
+}
@@ -0,0 +1,24 @@
+//! Tests for `TypedSwap`.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_swap_basic() {
+    let tuple = (1u8, 2u16, 3u32, 4u64, 5i8);
+    let swapped = TypedSwap::<TupleIndex1, TupleIndex3, _, _>::swap(tuple);
+    assert_eq!(swapped, (1u8, 4u64, 3u32, 2u16, 5i8));
+}
+
+#[test]
+fn test_swap_adjacent() {
+    let tuple = (1u8, 2u16, 3u32);
+    let swapped = TypedSwap::<TupleIndex0, TupleIndex1, _, _>::swap(tuple);
+    assert_eq!(swapped, (2u16, 1u8, 3u32));
+}
+
+#[test]
+fn test_swap_ends() {
+    let tuple = (1u8, 2u16, 3u32, 4u64);
+    let swapped = TypedSwap::<TupleIndex0, TupleIndex3, _, _>::swap(tuple);
+    assert_eq!(swapped, (4u64, 2u16, 3u32, 1u8));
+}
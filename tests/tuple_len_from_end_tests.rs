@@ -0,0 +1,31 @@
+//! Tests for `TupleLen` and `TypedFromEnd`.
+
+use typed_tuple::prelude::*;
+
+#[test]
+fn test_tuple_len() {
+    type MyTuple = (u8, u16, u32);
+    assert_eq!(<MyTuple as TupleLen>::LEN, 3);
+    type MyLen = <MyTuple as TupleLen>::Len;
+    assert_eq!(<MyLen as typenum::Unsigned>::to_usize(), 3);
+}
+
+#[test]
+fn test_tuple_len_empty() {
+    assert_eq!(<() as TupleLen>::LEN, 0);
+}
+
+#[test]
+fn test_get_from_end() {
+    type MyTuple = (u8, u16, u32);
+    let tuple: MyTuple = (1, 2, 3);
+
+    let last: &u32 = <MyTuple as TypedFromEnd<typenum::U0>>::get_from_end(&tuple);
+    assert_eq!(*last, 3);
+
+    let second_last: &u16 = <MyTuple as TypedFromEnd<typenum::U1>>::get_from_end(&tuple);
+    assert_eq!(*second_last, 2);
+
+    let third_last: &u8 = <MyTuple as TypedFromEnd<typenum::U2>>::get_from_end(&tuple);
+    assert_eq!(*third_last, 1);
+}
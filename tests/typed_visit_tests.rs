@@ -0,0 +1,51 @@
+//! Tests for the `Visitor`/`VisitMut`/`MapVisitor`/`TypedVisit` subsystem.
+
+use typed_tuple::prelude::*;
+
+struct Counter(usize);
+
+impl Visitor for Counter {
+    fn visit<T>(&mut self, _el: &T) {
+        self.0 += 1;
+    }
+}
+
+#[test]
+fn test_for_each() {
+    let tuple = (1u8, "hi", 2.5f32);
+    let mut counter = Counter(0);
+    tuple.for_each(&mut counter);
+    assert_eq!(counter.0, 3);
+}
+
+struct ResetToDefault;
+
+impl ElementVisitor for ResetToDefault {
+    fn visit<T: Default>(&mut self, el: &mut T) {
+        *el = T::default();
+    }
+}
+
+#[test]
+fn test_for_each_mut() {
+    let mut tuple = (1u8, 2u16, 3u32);
+    tuple.for_each_mut(&mut ResetToDefault);
+    assert_eq!(tuple, (0u8, 0u16, 0u32));
+}
+
+struct Stringify;
+
+impl MapVisitor for Stringify {
+    type Out<T> = String where T: core::fmt::Debug;
+
+    fn map<T: core::fmt::Debug>(&mut self, el: T) -> String {
+        format!("{el:?}")
+    }
+}
+
+#[test]
+fn test_map_all() {
+    let tuple = (1u8, 2u16);
+    let mapped = tuple.map_all(&mut Stringify);
+    assert_eq!(mapped, ("1".to_string(), "2".to_string()));
+}
@@ -0,0 +1,95 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+/// Implement `TupleIndexMul`/`TupleIndexMin`/`TupleIndexMax`/`TupleIndexLt`/
+/// `TupleIndexLe`/`TupleIndexEq`/`TupleIndexAdd`/`TupleIndexSub`/
+/// `TupleIndexSaturatingSub` for every pair of tuple indices `(I, J)` with
+/// `I, J <= n`, and `FromIndex<N>` for every `N <= n`.
+///
+/// `TupleIndexMul`/`TupleIndexAdd` are only implemented where the result
+/// (`I * J`/`I + J`) also falls within `0..=n`, and `TupleIndexSub` only
+/// where `I >= J`. `TupleIndexSaturatingSub` is implemented for every pair,
+/// saturating at `TupleIndex0`.
+pub(super) fn impl_arithmetic(n: usize) -> TokenStream {
+    let mut stream = (0..=n).fold(TokenStream::new(), |stream, i| {
+        (0..=n).fold(stream, |mut stream, j| {
+            let lhs = format_ident!("TupleIndex{i}");
+            let rhs = format_ident!("TupleIndex{j}");
+            let min = format_ident!("TupleIndex{}", i.min(j));
+            let max = format_ident!("TupleIndex{}", i.max(j));
+            let lt_out = if i < j { quote! { TrueIndex } } else { quote! { FalseIndex } };
+            let le_out = if i <= j { quote! { TrueIndex } } else { quote! { FalseIndex } };
+            let eq_out = if i == j { quote! { TrueIndex } } else { quote! { FalseIndex } };
+
+            stream.extend(TokenStream::from(quote! {
+                impl TupleIndexMin<#rhs> for #lhs {
+                    type Output = #min;
+                }
+
+                impl TupleIndexMax<#rhs> for #lhs {
+                    type Output = #max;
+                }
+
+                impl TupleIndexLt<#rhs> for #lhs {
+                    type Output = #lt_out;
+                }
+
+                impl TupleIndexLe<#rhs> for #lhs {
+                    type Output = #le_out;
+                }
+
+                impl TupleIndexEq<#rhs> for #lhs {
+                    type Output = #eq_out;
+                }
+            }));
+
+            if i * j <= n {
+                let product = format_ident!("TupleIndex{}", i * j);
+                stream.extend(TokenStream::from(quote! {
+                    impl TupleIndexMul<#rhs> for #lhs {
+                        type Output = #product;
+                    }
+                }));
+            }
+
+            if i + j <= n {
+                let sum = format_ident!("TupleIndex{}", i + j);
+                stream.extend(TokenStream::from(quote! {
+                    impl TupleIndexAdd<#rhs> for #lhs {
+                        type Output = #sum;
+                    }
+                }));
+            }
+
+            if i >= j {
+                let difference = format_ident!("TupleIndex{}", i - j);
+                stream.extend(TokenStream::from(quote! {
+                    impl TupleIndexSub<#rhs> for #lhs {
+                        type Output = #difference;
+                    }
+                }));
+            }
+
+            let saturating_difference = format_ident!("TupleIndex{}", i.saturating_sub(j));
+            stream.extend(TokenStream::from(quote! {
+                impl TupleIndexSaturatingSub<#rhs> for #lhs {
+                    type Output = #saturating_difference;
+                }
+            }));
+
+            stream
+        })
+    });
+
+    stream.extend((0..=n).fold(TokenStream::new(), |mut stream, i| {
+        let marker = format_ident!("TupleIndex{i}");
+        stream.extend(TokenStream::from(quote! {
+            impl FromIndex<#i> for () {
+                type Output = #marker;
+            }
+        }));
+        stream
+    }));
+
+    stream
+}
@@ -0,0 +1,30 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TupleVisit` for a tuple of `n` elements.
+pub(super) fn impl_element_visit(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    let visits = indices.iter().map(|i| quote! { v.visit(&mut self.#i); });
+    let fold_steps = indices
+        .iter()
+        .map(|i| quote! { let acc = v.fold(acc, &self.#i); });
+
+    TokenStream::from(quote! {
+        impl< #( #generics, )* > TupleVisit for ( #( #generics, )* ) {
+            #[inline]
+            fn for_each<V: ElementVisitor>(&mut self, v: &mut V) {
+                #( #visits )*
+            }
+
+            #[inline]
+            fn fold<V: ElementFolder<Acc>, Acc>(&self, v: &mut V, init: Acc) -> Acc {
+                let acc = init;
+                #( #fold_steps )*
+                acc
+            }
+        }
+    })
+}
@@ -0,0 +1,19 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::Index;
+
+/// Implement `Homogeneous` for a tuple of `n` elements, all of type `T`.
+pub(super) fn impl_homogeneous(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let fields = (0..n).map(|_| quote! { T }).collect::<Vec<_>>();
+
+    TokenStream::from(quote! {
+        impl<T> Homogeneous<T> for ( #( #fields, )* ) {
+            const LEN: usize = #n;
+
+            fn into_array(self) -> [T; #n] {
+                [ #( self.#indices, )* ]
+            }
+        }
+    })
+}
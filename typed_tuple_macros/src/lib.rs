@@ -1,11 +1,29 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use quote::format_ident;
 use syn::*;
 
+mod arithmetic;
+mod element_visit;
+mod homogeneous;
+mod named_tuple;
+mod tuple_ops;
+mod tuple_visit;
+mod typed_bound;
 mod typed_extract;
+mod typed_from_end;
 mod typed_index;
+mod typed_join;
+mod typed_len;
+mod typed_match_type;
+mod typed_reverse;
+mod typed_slice;
 mod typed_split;
+mod typed_visit;
+mod typed_zip;
+mod tuple_index;
+mod tuple_len;
 
 /// Implement `TypedIndex` on tuples of fields less than or equal to the given
 /// integer literal.
@@ -17,10 +35,15 @@ mod typed_split;
 #[proc_macro]
 pub fn impl_typed_index(item: TokenStream) -> TokenStream {
     match parse_int(item).map_err(|e| e.into_compile_error()) {
-        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
-            stream.extend(typed_index::impl_typed_index(i));
-            stream
-        }),
+        Ok(n) => {
+            let indices = (0..=n).map(Index::from).collect::<Vec<_>>();
+            let generics = (0..=n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+            (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+                stream.extend(typed_index::impl_typed_index(i, &indices, &generics));
+                stream
+            })
+        }
         Err(e) => e.into(),
     }
 }
@@ -35,8 +58,31 @@ pub fn impl_typed_index(item: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn impl_typed_split(item: TokenStream) -> TokenStream {
     match parse_int(item).map_err(|e| e.into_compile_error()) {
-        Ok(n) => (1..n + 1).fold(TokenStream::new(), |mut stream, i| {
-            stream.extend(typed_split::impl_typed_split(i));
+        Ok(n) => {
+            let indices = (0..=n).map(Index::from).collect::<Vec<_>>();
+            let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+            (1..n + 1).fold(TokenStream::new(), |mut stream, i| {
+                stream.extend(typed_split::impl_typed_split(i, &indices, &generics));
+                stream
+            })
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedSlice` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_slice!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_slice(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_slice::impl_typed_slice(i));
             stream
         }),
         Err(e) => e.into(),
@@ -52,15 +98,301 @@ pub fn impl_typed_split(item: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro]
 pub fn impl_typed_extract(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => {
+            let indices = (0..=n).map(Index::from).collect::<Vec<_>>();
+            let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+            (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+                stream.extend(typed_extract::impl_typed_extract(i, &indices, &generics));
+                stream
+            })
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `Homogeneous` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_homogeneous!(12); // Implement on tuples of 1 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_homogeneous(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (1..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(homogeneous::impl_homogeneous(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedZip`/`TypedUnzip` on tuples of fields less than or equal
+/// to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_zip!(12); // Implement on tuples of 1 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_zip(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (1..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_zip::impl_typed_zip(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TupleMap`/`TupleFold` on tuples of fields less than or equal
+/// to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_tuple_visit!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_tuple_visit(item: TokenStream) -> TokenStream {
     match parse_int(item).map_err(|e| e.into_compile_error()) {
         Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
-            stream.extend(typed_extract::impl_typed_extract(i));
+            stream.extend(tuple_visit::impl_tuple_visit(i));
             stream
         }),
         Err(e) => e.into(),
     }
 }
 
+/// Implement `core::ops::{Add, Sub, Mul, Div}` element-wise on tuples of
+/// fields less than or equal to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_tuple_ops!(12); // Implement on tuples of 1 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_tuple_ops(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (1..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(tuple_ops::impl_tuple_ops(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TupleVisit` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_element_visit!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_element_visit(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(element_visit::impl_element_visit(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedBound` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_bound!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_bound(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_bound::impl_typed_bound(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedJoin` on every pair of tuples whose combined arity is
+/// less than or equal to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_join!(12); // Implement for combined arities of up to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_join(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => typed_join::impl_typed_join(n),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedMatchType` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_match_type!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_match_type(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_match_type::impl_typed_match_type(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedVisit` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_visit!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_visit(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_visit::impl_typed_visit(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedLen` on tuples of fields less than or equal to the given
+/// integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_len!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_len(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_len::impl_typed_len(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedReverse` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_reverse!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_reverse(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_reverse::impl_typed_reverse(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `NamedTuple`/`MatchName` on tuples of fields less than or
+/// equal to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_named_tuple!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_named_tuple(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(named_tuple::impl_named_tuple(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TupleIndexMul`/`TupleIndexMin`/`TupleIndexMax`/`TupleIndexLt`/
+/// `TupleIndexLe`/`TupleIndexEq`/`FromIndex` for tuple indices less than or
+/// equal to the given integer literal.
+///
+/// # Example
+/// ```
+/// impl_arithmetic!(64); // Implement for indices 0 to 64.
+/// ```
+#[proc_macro]
+pub fn impl_arithmetic(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => arithmetic::impl_arithmetic(n),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TupleLen` on tuples of fields less than or equal to the given
+/// integer literal.
+///
+/// # Example
+/// ```
+/// impl_tuple_len!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_tuple_len(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(tuple_len::impl_tuple_len(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Implement `TypedFromEnd` on tuples of fields less than or equal to the
+/// given integer literal.
+///
+/// # Example
+/// ```
+/// impl_typed_from_end!(12); // Implement on tuples of 0 to 12 fields.
+/// ```
+#[proc_macro]
+pub fn impl_typed_from_end(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => (0..n + 1).fold(TokenStream::new(), |mut stream, i| {
+            stream.extend(typed_from_end::impl_typed_from_end(i));
+            stream
+        }),
+        Err(e) => e.into(),
+    }
+}
+
+/// Generate the concrete `TupleIndex0`..`TupleIndexN` marker types (and
+/// their `TupleIndex` impl) for indices up to and including the given
+/// integer literal.
+///
+/// # Example
+/// ```
+/// impl_tuple_index!(64); // Generate TupleIndex0..TupleIndex64.
+/// ```
+#[proc_macro]
+pub fn impl_tuple_index(item: TokenStream) -> TokenStream {
+    match parse_int(item).map_err(|e| e.into_compile_error()) {
+        Ok(n) => tuple_index::impl_tuple_index(n),
+        Err(e) => e.into(),
+    }
+}
+
 /// Parse an (unsigned) integer literal input.
 fn parse_int(item: TokenStream) -> syn::Result<usize> {
     let lit = syn::parse::<ExprLit>(item)?;
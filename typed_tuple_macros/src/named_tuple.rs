@@ -0,0 +1,61 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `NamedTuple`/`MatchName` for a tuple of `n` elements, each
+/// bounded by `NamedElement`.
+pub(super) fn impl_named_tuple(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+    let all_generics = quote! { #( #generics, )* };
+    let bounds = generics.iter().map(|t| quote! { #t: NamedElement, #t::Value: 'static });
+
+    let name_list = generics.iter().map(|t| quote! { #t::NAME });
+
+    let ref_checks = indices.iter().zip(&generics).map(|(i, t)| {
+        quote! {
+            if #t::NAME == name {
+                if let Some(val) = (self.#i.value() as &dyn core::any::Any).downcast_ref::<T>() {
+                    return Some(val);
+                }
+            }
+        }
+    });
+    let mut_checks = indices.iter().zip(&generics).map(|(i, t)| {
+        quote! {
+            if #t::NAME == name {
+                if let Some(val) =
+                    (self.#i.value_mut() as &mut dyn core::any::Any).downcast_mut::<T>()
+                {
+                    return Some(val);
+                }
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        impl< #all_generics > NamedTuple for ( #all_generics )
+        where
+            #( #bounds, )*
+        {
+            fn names() -> &'static [&'static str] {
+                &[ #( #name_list, )* ]
+            }
+        }
+
+        impl< #all_generics > MatchName for ( #all_generics )
+        where
+            #( #bounds, )*
+        {
+            fn match_name<T: 'static>(&self, name: &str) -> Option<&T> {
+                #( #ref_checks )*
+                None
+            }
+
+            fn match_name_mut<T: 'static>(&mut self, name: &str) -> Option<&mut T> {
+                #( #mut_checks )*
+                None
+            }
+        }
+    })
+}
@@ -0,0 +1,28 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generate the concrete `TupleIndex0`..`TupleIndex{n}` marker types and
+/// their `TupleIndex` impl.
+///
+/// Every other trait keyed on `TupleIndex` (`TupleIndexAdd`, `TupleIndexSub`,
+/// `TupleIndexMul`, ...) is implemented in terms of these
+/// concrete markers, so they need to exist before any of those traits can
+/// have inhabitants.
+pub(super) fn impl_tuple_index(n: usize) -> TokenStream {
+    (0..=n).fold(TokenStream::new(), |mut stream, i| {
+        let marker = format_ident!("TupleIndex{i}");
+        let doc = format!("Concrete marker type for tuple index {i}.");
+
+        stream.extend(TokenStream::from(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct #marker;
+
+            impl TupleIndex for #marker {
+                const INDEX: usize = #i;
+            }
+        }));
+
+        stream
+    })
+}
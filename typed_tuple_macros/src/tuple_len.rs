@@ -0,0 +1,15 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+/// Implement `TupleLen` for a tuple of `n` elements.
+pub(super) fn impl_tuple_len(n: usize) -> TokenStream {
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+    let len_ty = format_ident!("U{n}");
+
+    TokenStream::from(quote! {
+        impl< #( #generics, )* > TupleLen for ( #( #generics, )* ) {
+            type Len = typenum::#len_ty;
+            const LEN: usize = #n;
+        }
+    })
+}
@@ -0,0 +1,51 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// The `core::ops` traits implemented element-wise across tuples.
+const OPS: [(&str, &str); 4] = [
+    ("Add", "add"),
+    ("Sub", "sub"),
+    ("Mul", "mul"),
+    ("Div", "div"),
+];
+
+/// Implement `core::ops::{Add, Sub, Mul, Div}` element-wise for a tuple of
+/// `n` elements.
+pub(super) fn impl_tuple_ops(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let lhs = (0..n).map(|i| format_ident!("A{i}")).collect::<Vec<_>>();
+    let rhs = (0..n).map(|i| format_ident!("B{i}")).collect::<Vec<_>>();
+
+    OPS.iter().fold(TokenStream::new(), |mut stream, (trait_name, method_name)| {
+        let trait_ident = format_ident!("{trait_name}");
+        let method_ident = format_ident!("{method_name}");
+
+        let bounds = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(a, b)| quote! { #a: ::core::ops::#trait_ident<#b> });
+        let outputs = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(a, b)| quote! { <#a as ::core::ops::#trait_ident<#b>>::Output });
+        let calls = indices
+            .iter()
+            .map(|i| quote! { ::core::ops::#trait_ident::#method_ident(self.#i, rhs.#i) });
+
+        stream.extend(TokenStream::from(quote! {
+            impl< #( #lhs, )* #( #rhs, )* > ::core::ops::#trait_ident<( #( #rhs, )* )> for ( #( #lhs, )* )
+            where
+                #( #bounds, )*
+            {
+                type Output = ( #( #outputs, )* );
+
+                fn #method_ident(self, rhs: ( #( #rhs, )* )) -> Self::Output {
+                    ( #( #calls, )* )
+                }
+            }
+        }));
+
+        stream
+    })
+}
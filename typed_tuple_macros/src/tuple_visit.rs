@@ -0,0 +1,44 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TupleMap` and `TupleFold` for a tuple of `n` elements.
+pub(super) fn impl_tuple_visit(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    let map_bounds = generics.iter().map(|t| quote! { M: Mapper<#t> });
+    let map_fields = indices.iter().map(|i| quote! { m.map(self.#i) });
+    let map_output = generics
+        .iter()
+        .map(|t| quote! { <M as Mapper<#t>>::Out });
+
+    let fold_bounds = generics.iter().map(|t| quote! { F: Folder<Acc, #t> });
+    let fold_steps = indices
+        .iter()
+        .map(|i| quote! { let acc = f.fold(acc, self.#i); });
+
+    TokenStream::from(quote! {
+        impl<M, #( #generics, )* > TupleMap<M> for ( #( #generics, )* )
+        where
+            #( #map_bounds, )*
+        {
+            type Output = ( #( #map_output, )* );
+
+            fn map_each(self, mut m: M) -> Self::Output {
+                ( #( #map_fields, )* )
+            }
+        }
+
+        impl< #( #generics, )* > TupleFold for ( #( #generics, )* ) {
+            fn fold_each<Acc, F>(self, init: Acc, mut f: F) -> Acc
+            where
+                #( #fold_bounds, )*
+            {
+                let acc = init;
+                #( #fold_steps )*
+                acc
+            }
+        }
+    })
+}
@@ -0,0 +1,27 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedFromEnd<typenum::U{k}>` for a tuple of `n` elements, for
+/// every valid `k` in `0..n`.
+pub(super) fn impl_typed_from_end(n: usize) -> TokenStream {
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    (0..n).fold(TokenStream::new(), |mut stream, k| {
+        let marker = format_ident!("U{k}");
+        let field_ty = &generics[n - 1 - k];
+        let field = Index::from(n - 1 - k);
+
+        stream.extend(TokenStream::from(quote! {
+            impl< #( #generics, )* > TypedFromEnd<typenum::#marker> for ( #( #generics, )* ) {
+                type FromEndType = #field_ty;
+
+                fn get_from_end(&self) -> &Self::FromEndType {
+                    &self.#field
+                }
+            }
+        }));
+
+        stream
+    })
+}
@@ -0,0 +1,32 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+/// Implement `TypedJoin` for every pair of tuples `(A0..Ai)`, `(B0..Bj)`
+/// with `i + j <= n`.
+pub(super) fn impl_typed_join(n: usize) -> TokenStream {
+    (0..=n).fold(TokenStream::new(), |stream, i| {
+        (0..=(n - i)).fold(stream, |mut stream, j| {
+            let lhs_generics = (0..i).map(|k| format_ident!("A{k}")).collect::<Vec<_>>();
+            let rhs_generics = (0..j).map(|k| format_ident!("B{k}")).collect::<Vec<_>>();
+            let all_generics = quote! { #( #lhs_generics, )* #( #rhs_generics, )* };
+
+            let lhs_fields = (0..i).map(syn::Index::from).collect::<Vec<_>>();
+            let rhs_fields = (0..j).map(syn::Index::from).collect::<Vec<_>>();
+
+            stream.extend(TokenStream::from(quote! {
+                impl< #all_generics > TypedJoin<( #( #rhs_generics, )* )> for ( #( #lhs_generics, )* ) {
+                    type Output = ( #( #lhs_generics, )* #( #rhs_generics, )* );
+
+                    fn join(self, rhs: ( #( #rhs_generics, )* )) -> Self::Output {
+                        (
+                            #( self.#lhs_fields, )*
+                            #( rhs.#rhs_fields, )*
+                        )
+                    }
+                }
+            }));
+
+            stream
+        })
+    })
+}
@@ -0,0 +1,13 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+
+/// Implement `TypedLen` for a tuple of `n` elements.
+pub(super) fn impl_typed_len(n: usize) -> TokenStream {
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    TokenStream::from(quote! {
+        impl< #( #generics, )* > TypedLen for ( #( #generics, )* ) {
+            const LEN: usize = #n;
+        }
+    })
+}
@@ -0,0 +1,42 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedMatchType` for a tuple of `n` elements.
+pub(super) fn impl_typed_match_type(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+    let bounds = generics.iter().map(|t| quote! { #t: 'static });
+
+    let ref_checks = indices.iter().map(|i| {
+        quote! {
+            if let Some(val) = (&self.#i as &dyn core::any::Any).downcast_ref::<T>() {
+                return Some(val);
+            }
+        }
+    });
+    let mut_checks = indices.iter().map(|i| {
+        quote! {
+            if let Some(val) = (&mut self.#i as &mut dyn core::any::Any).downcast_mut::<T>() {
+                return Some(val);
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        impl< #( #generics, )* > TypedMatchType for ( #( #generics, )* )
+        where
+            #( #bounds, )*
+        {
+            fn match_first_type<T: 'static>(&self) -> Option<&T> {
+                #( #ref_checks )*
+                None
+            }
+
+            fn match_first_type_mut<T: 'static>(&mut self) -> Option<&mut T> {
+                #( #mut_checks )*
+                None
+            }
+        }
+    })
+}
@@ -0,0 +1,40 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedReverse` for a tuple of `n` elements.
+pub(super) fn impl_typed_reverse(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    let rev_indices = indices.iter().rev().collect::<Vec<_>>();
+    let rev_generics = generics.iter().rev().collect::<Vec<_>>();
+
+    let all_generics = quote! { #( #generics, )* };
+
+    TokenStream::from(quote! {
+        impl< #all_generics > TypedReverse for ( #all_generics ) {
+            type Output = ( #( #rev_generics, )* );
+
+            fn reverse(self) -> Self::Output {
+                ( #( self.#rev_indices, )* )
+            }
+        }
+
+        impl<'a, #all_generics > TypedReverse for &'a ( #all_generics ) {
+            type Output = ( #( &'a #rev_generics, )* );
+
+            fn reverse(self) -> Self::Output {
+                ( #( &self.#rev_indices, )* )
+            }
+        }
+
+        impl<'a, #all_generics > TypedReverse for &'a mut ( #all_generics ) {
+            type Output = ( #( &'a mut #rev_generics, )* );
+
+            fn reverse(self) -> Self::Output {
+                ( #( &mut self.#rev_indices, )* )
+            }
+        }
+    })
+}
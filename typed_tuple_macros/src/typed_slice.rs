@@ -0,0 +1,65 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedSlice` for every `(START, END)` range of a tuple of `n`
+/// elements.
+pub(super) fn impl_typed_slice(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    (0..=n).fold(TokenStream::new(), |stream, start| {
+        (start..=n).fold(stream, |mut stream, end| {
+            let (idx_left, rest) = indices.split_at(start);
+            let (idx_mid, idx_right) = rest.split_at(end - start);
+
+            let (gen_left, gen_rest) = generics.split_at(start);
+            let (gen_mid, gen_right) = gen_rest.split_at(end - start);
+
+            let all_generics = quote! { #( #generics, )* };
+            let left_ty = quote! { ( #( #gen_left, )* ) };
+            let mid_ty = quote! { ( #( #gen_mid, )* ) };
+            let right_ty = quote! { ( #( #gen_right, )* ) };
+
+            stream.extend(TokenStream::from(quote! {
+                impl< #all_generics > TypedSlice<#start, #end, #left_ty, #mid_ty, #right_ty>
+                    for ( #all_generics )
+                {
+                    fn slice(self) -> (#left_ty, #mid_ty, #right_ty) {
+                        (
+                            ( #( self.#idx_left, )* ),
+                            ( #( self.#idx_mid, )* ),
+                            ( #( self.#idx_right, )* ),
+                        )
+                    }
+                }
+
+                impl<'a, #all_generics > TypedSlice<#start, #end, ( #( &'a #gen_left, )* ), ( #( &'a #gen_mid, )* ), ( #( &'a #gen_right, )* )>
+                    for &'a ( #all_generics )
+                {
+                    fn slice(self) -> (( #( &'a #gen_left, )* ), ( #( &'a #gen_mid, )* ), ( #( &'a #gen_right, )* )) {
+                        (
+                            ( #( &self.#idx_left, )* ),
+                            ( #( &self.#idx_mid, )* ),
+                            ( #( &self.#idx_right, )* ),
+                        )
+                    }
+                }
+
+                impl<'a, #all_generics > TypedSlice<#start, #end, ( #( &'a mut #gen_left, )* ), ( #( &'a mut #gen_mid, )* ), ( #( &'a mut #gen_right, )* )>
+                    for &'a mut ( #all_generics )
+                {
+                    fn slice(self) -> (( #( &'a mut #gen_left, )* ), ( #( &'a mut #gen_mid, )* ), ( #( &'a mut #gen_right, )* )) {
+                        (
+                            ( #( &mut self.#idx_left, )* ),
+                            ( #( &mut self.#idx_mid, )* ),
+                            ( #( &mut self.#idx_right, )* ),
+                        )
+                    }
+                }
+            }));
+
+            stream
+        })
+    })
+}
@@ -0,0 +1,27 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedVisit` for a tuple of `n` elements.
+pub(super) fn impl_typed_visit(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let generics = (0..n).map(|i| format_ident!("T{i}")).collect::<Vec<_>>();
+
+    let visits = indices.iter().map(|i| quote! { v.visit(&self.#i); });
+    let map_fields = indices.iter().map(|i| quote! { v.map(self.#i) });
+    let map_output = generics.iter().map(|t| quote! { V::Out<#t> });
+
+    TokenStream::from(quote! {
+        impl< #( #generics, )* > TypedVisit for ( #( #generics, )* ) {
+            type MapOutput<V: MapVisitor> = ( #( #map_output, )* );
+
+            fn for_each<V: Visitor>(&self, v: &mut V) {
+                #( #visits )*
+            }
+
+            fn map_all<V: MapVisitor>(self, v: &mut V) -> Self::MapOutput<V> {
+                ( #( #map_fields, )* )
+            }
+        }
+    })
+}
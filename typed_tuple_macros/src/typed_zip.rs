@@ -0,0 +1,35 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::Index;
+
+/// Implement `TypedZip` and `TypedUnzip` for a tuple of `n` elements.
+pub(super) fn impl_typed_zip(n: usize) -> TokenStream {
+    let indices = (0..n).map(Index::from).collect::<Vec<_>>();
+    let lhs = (0..n).map(|i| format_ident!("A{i}")).collect::<Vec<_>>();
+    let rhs = (0..n).map(|i| format_ident!("B{i}")).collect::<Vec<_>>();
+
+    let pairs = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(a, b)| quote! { (#a, #b) })
+        .collect::<Vec<_>>();
+
+    TokenStream::from(quote! {
+        impl< #( #lhs, )* #( #rhs, )* > TypedZip<( #( #rhs, )* )> for ( #( #lhs, )* ) {
+            type Output = ( #( #pairs, )* );
+
+            fn zip(self, other: ( #( #rhs, )* )) -> Self::Output {
+                ( #( (self.#indices, other.#indices), )* )
+            }
+        }
+
+        impl< #( #lhs, )* #( #rhs, )* > TypedUnzip for ( #( #pairs, )* ) {
+            type Left = ( #( #lhs, )* );
+            type Right = ( #( #rhs, )* );
+
+            fn unzip(self) -> (Self::Left, Self::Right) {
+                ( ( #( self.#indices.0, )* ), ( #( self.#indices.1, )* ) )
+            }
+        }
+    })
+}